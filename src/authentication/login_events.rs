@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, Debug)]
+pub enum LoginOutcome {
+    Success,
+    Failure,
+}
+
+impl LoginOutcome {
+    fn as_str(self) -> &'static str {
+        match self {
+            LoginOutcome::Success => "success",
+            LoginOutcome::Failure => "failure",
+        }
+    }
+}
+
+/// Records one authentication attempt. `user_id` is `None` for failed
+/// attempts against an unknown username, since there is nothing to
+/// attribute the event to.
+#[tracing::instrument(name = "Record login event", skip(pool))]
+pub async fn record_login_event(
+    user_id: Option<Uuid>,
+    ip: &str,
+    user_agent: Option<&str>,
+    outcome: LoginOutcome,
+    pool: &PgPool,
+) -> Result<(), anyhow::Error> {
+    sqlx::query!(
+        r#"
+        INSERT INTO login_events (user_id, ip, user_agent, outcome)
+        VALUES ($1, $2, $3, $4)
+        "#,
+        user_id,
+        ip,
+        user_agent,
+        outcome.as_str(),
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub struct LastLogin {
+    pub ip: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Returns the successful login *before* the one that just happened.
+///
+/// By the time this runs, `record_login_event` has already inserted a row
+/// for the current session, so the most recent row is always "now" - not
+/// useful for "spot suspicious access" on the dashboard. `OFFSET 1` skips
+/// that just-recorded row to surface the previous login instead.
+#[tracing::instrument(name = "Get last successful login", skip(pool))]
+pub async fn get_last_successful_login(
+    user_id: Uuid,
+    pool: &PgPool,
+) -> Result<Option<LastLogin>, anyhow::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT ip, created_at
+        FROM login_events
+        WHERE user_id = $1 AND outcome = 'success'
+        ORDER BY created_at DESC
+        OFFSET 1 LIMIT 1
+        "#,
+        user_id,
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|row| LastLogin {
+        ip: row.ip,
+        created_at: row.created_at,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_last_successful_login, record_login_event, LoginOutcome};
+    use crate::test_helpers::insert_test_user;
+
+    #[sqlx::test]
+    async fn no_successful_logins_yet_returns_none(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+        record_login_event(Some(user_id), "127.0.0.1", None, LoginOutcome::Success, &pool)
+            .await
+            .unwrap();
+
+        // Only the just-recorded login exists - OFFSET 1 skips it, so
+        // there's no *previous* login to report yet.
+        let last = get_last_successful_login(user_id, &pool).await.unwrap();
+        assert!(last.is_none());
+    }
+
+    #[sqlx::test]
+    async fn second_login_surfaces_the_first(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+        record_login_event(
+            Some(user_id),
+            "203.0.113.1",
+            Some("curl/8.0"),
+            LoginOutcome::Success,
+            &pool,
+        )
+        .await
+        .unwrap();
+        record_login_event(Some(user_id), "203.0.113.2", None, LoginOutcome::Success, &pool)
+            .await
+            .unwrap();
+
+        let last = get_last_successful_login(user_id, &pool)
+            .await
+            .unwrap()
+            .expect("a previous login should be present");
+        assert_eq!(last.ip, "203.0.113.1");
+    }
+
+    #[sqlx::test]
+    async fn failed_attempts_are_not_surfaced_as_last_login(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+        record_login_event(Some(user_id), "203.0.113.1", None, LoginOutcome::Success, &pool)
+            .await
+            .unwrap();
+        record_login_event(Some(user_id), "203.0.113.2", None, LoginOutcome::Failure, &pool)
+            .await
+            .unwrap();
+
+        let last = get_last_successful_login(user_id, &pool).await.unwrap();
+        assert!(last.is_none());
+    }
+}
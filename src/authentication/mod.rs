@@ -0,0 +1,11 @@
+mod login_events;
+mod password;
+mod reset_token;
+mod verification_token;
+
+pub use login_events::{get_last_successful_login, record_login_event, LastLogin, LoginOutcome};
+pub use password::{
+    change_password, compute_password_hash, validate_credentials, AuthError, Credentials,
+};
+pub use reset_token::{generate_token, hash_token};
+pub use verification_token::generate_verification_token;
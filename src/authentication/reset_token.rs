@@ -0,0 +1,21 @@
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Generates a cryptographically random, URL-safe token to embed in a
+/// password-reset (or email-verification) link.
+///
+/// Only the SHA-256 hash of this value is ever persisted - the raw token
+/// exists solely in the URL we email to the user, so a database leak alone
+/// can't be used to complete a reset.
+pub fn generate_token() -> String {
+    rand::thread_rng()
+        .sample_iter(Alphanumeric)
+        .take(48)
+        .map(char::from)
+        .collect()
+}
+
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
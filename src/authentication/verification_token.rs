@@ -0,0 +1,12 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::RngCore;
+
+/// Generates a random token encoded as URL-safe base64 without padding, so
+/// it can be dropped directly into a query string (`?token=...`) without
+/// any further percent-escaping.
+pub fn generate_verification_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use secrecy::Secret;
+
+use crate::domain::SubscriberEmail;
+use crate::email_client::{EmailTransport, PostmarkEmailClient, RetryPolicy, SmtpEmailClient, TlsSecurity};
+
+#[derive(serde::Deserialize, Clone)]
+pub struct Settings {
+    pub email_client: EmailClientSettings,
+    pub application_base_url: String,
+    /// Header a trusted reverse proxy sets with the original client IP
+    /// (e.g. `X-Forwarded-For`). `None` means read `peer_addr` directly,
+    /// which is only correct when nothing sits in front of the app.
+    pub trusted_ip_header: Option<String>,
+}
+
+/// The externally-reachable base URL of this application, used to build
+/// links embedded in outgoing emails (password resets, confirmations, ...).
+#[derive(Clone, Debug)]
+pub struct ApplicationBaseUrl(pub String);
+
+#[derive(serde::Deserialize, Clone)]
+pub struct EmailClientSettings {
+    pub sender_email: String,
+    pub timeout_milliseconds: u64,
+    #[serde(flatten)]
+    pub retry: RetrySettings,
+    #[serde(flatten)]
+    pub transport: EmailTransportSettings,
+}
+
+#[derive(serde::Deserialize, Clone)]
+pub struct RetrySettings {
+    pub retry_max_attempts: u32,
+    pub retry_base_delay_milliseconds: u64,
+}
+
+impl EmailClientSettings {
+    pub fn sender(&self) -> Result<SubscriberEmail, String> {
+        SubscriberEmail::parse(self.sender_email.clone())
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_milliseconds)
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: self.retry.retry_max_attempts,
+            base_delay: Duration::from_millis(self.retry.retry_base_delay_milliseconds),
+        }
+    }
+
+    /// Builds the `EmailTransport` selected by this configuration.
+    ///
+    /// Centralising the decision here means the rest of the application
+    /// only ever needs to depend on `dyn EmailTransport`.
+    pub fn client(&self) -> Result<Box<dyn EmailTransport>, anyhow::Error> {
+        let sender = self
+            .sender()
+            .map_err(|e| anyhow::anyhow!("invalid sender email: {e}"))?;
+
+        let client: Box<dyn EmailTransport> = match &self.transport {
+            EmailTransportSettings::Postmark { base_url, auth_token } => {
+                Box::new(PostmarkEmailClient::new(
+                    base_url.clone(),
+                    sender,
+                    auth_token.clone(),
+                    self.timeout(),
+                    self.retry_policy(),
+                ))
+            }
+            EmailTransportSettings::Smtp {
+                relay,
+                port,
+                security,
+                username,
+                password,
+            } => Box::new(SmtpEmailClient::new(
+                relay,
+                *port,
+                *security,
+                username.clone(),
+                password.clone(),
+                sender,
+            )?),
+        };
+        Ok(client)
+    }
+}
+
+#[derive(serde::Deserialize, Clone)]
+#[serde(tag = "transport", rename_all = "snake_case")]
+pub enum EmailTransportSettings {
+    Postmark {
+        base_url: String,
+        auth_token: Secret<String>,
+    },
+    Smtp {
+        relay: String,
+        port: u16,
+        security: TlsSecurity,
+        username: String,
+        password: Secret<String>,
+    },
+}
@@ -0,0 +1,60 @@
+mod postmark;
+mod smtp;
+
+pub use postmark::PostmarkEmailClient;
+pub use smtp::{SmtpEmailClient, TlsSecurity};
+
+use crate::domain::SubscriberEmail;
+use rand::Rng;
+use std::time::Duration;
+
+/// A provider-agnostic way of delivering an email.
+///
+/// Every concrete transport (Postmark's HTTP API, SMTP, ...) implements this
+/// trait so callers can depend on `dyn EmailTransport` and swap the backend
+/// at startup purely via configuration.
+#[async_trait::async_trait]
+pub trait EmailTransport: Send + Sync {
+    async fn send_email(
+        &self,
+        recipient: SubscriberEmail,
+        subject: &str,
+        html_content: &str,
+        text_content: &str,
+    ) -> Result<(), EmailError>;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum EmailError {
+    #[error("failed to reach the email provider")]
+    Transport(#[source] anyhow::Error),
+    #[error("the email provider rejected the request")]
+    Rejected(#[source] anyhow::Error),
+}
+
+/// How hard a transport should retry a transient failure before giving up.
+///
+/// Delays follow exponential backoff (`base_delay * 2^attempt`) capped at a
+/// few seconds, with +/-50% jitter so a burst of retrying clients doesn't
+/// hammer the provider in lockstep.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    const MAX_DELAY: Duration = Duration::from_secs(5);
+
+    /// Computes the backoff delay before the given (1-indexed) attempt.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let unjittered = self
+            .base_delay
+            .saturating_mul(1u32.checked_shl(exponent).unwrap_or(u32::MAX))
+            .min(Self::MAX_DELAY);
+
+        let jitter = rand::thread_rng().gen_range(0.5..1.5);
+        unjittered.mul_f64(jitter).min(Self::MAX_DELAY)
+    }
+}
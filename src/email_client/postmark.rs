@@ -1,6 +1,8 @@
 use crate::domain::SubscriberEmail;
-use reqwest::Client;
+use crate::email_client::{EmailError, EmailTransport, RetryPolicy};
+use reqwest::{Client, StatusCode};
 use secrecy::{ExposeSecret, Secret};
+use std::time::Duration;
 
 #[derive(serde::Serialize)]
 #[serde(rename_all = "PascalCase")]
@@ -12,35 +14,43 @@ struct SendEmailRequest<'a> {
     text_body: &'a str,
 }
 
+/// Delivers email through Postmark's HTTP API.
 #[derive(Debug)]
-pub struct EmailClient {
+pub struct PostmarkEmailClient {
     http_client: Client,
     base_url: String,
     sender: SubscriberEmail,
     authorization_token: Secret<String>,
+    retry_policy: RetryPolicy,
 }
 
-impl EmailClient {
+impl PostmarkEmailClient {
     pub fn new(
         base_url: String,
         sender: SubscriberEmail,
         authorization_token: Secret<String>,
+        timeout: Duration,
+        retry_policy: RetryPolicy,
     ) -> Self {
         Self {
-            http_client: Client::new(),
+            http_client: Client::builder()
+                .timeout(timeout)
+                .build()
+                .expect("Failed to build the Postmark HTTP client"),
             base_url,
             sender,
             authorization_token,
+            retry_policy,
         }
     }
 
-    pub async fn send_email(
+    async fn send_once(
         &self,
-        recipient: SubscriberEmail,
+        recipient: &SubscriberEmail,
         subject: &str,
         html_content: &str,
         text_content: &str,
-    ) -> Result<(), reqwest::Error> {
+    ) -> Result<(), EmailError> {
         use reqwest::Url;
 
         let base_url = Url::parse(&self.base_url).unwrap();
@@ -54,7 +64,8 @@ impl EmailClient {
             text_body: text_content,
         };
 
-        self.http_client
+        let response = self
+            .http_client
             .post(url)
             .header(
                 "X-Postmark-Server-Token",
@@ -62,22 +73,77 @@ impl EmailClient {
             )
             .json(&request_body)
             .send()
-            .await?
-            .error_for_status()?;
+            .await
+            .map_err(|e| EmailError::Transport(e.into()))?;
+
+        response
+            .error_for_status()
+            .map_err(|e| {
+                if is_retryable_status(e.status()) {
+                    EmailError::Transport(e.into())
+                } else {
+                    EmailError::Rejected(e.into())
+                }
+            })?;
         Ok(())
     }
 }
 
+fn is_retryable_status(status: Option<StatusCode>) -> bool {
+    match status {
+        Some(status) => status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS,
+        None => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailTransport for PostmarkEmailClient {
+    async fn send_email(
+        &self,
+        recipient: SubscriberEmail,
+        subject: &str,
+        html_content: &str,
+        text_content: &str,
+    ) -> Result<(), EmailError> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self
+                .send_once(&recipient, subject, html_content, text_content)
+                .await
+            {
+                Ok(()) => return Ok(()),
+                // Only transient failures (connection/timeout errors, 5xx, 429)
+                // are retried - anything else (e.g. a 4xx) is a permanent
+                // rejection and retrying it would just waste the attempt budget.
+                Err(EmailError::Transport(e)) if attempt < self.retry_policy.max_attempts => {
+                    let delay = self.retry_policy.backoff(attempt);
+                    tracing::warn!(
+                        error = %e,
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        "Transient failure sending email, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::domain::SubscriberEmail;
-    use crate::email_client::EmailClient;
+    use crate::email_client::postmark::PostmarkEmailClient;
+    use crate::email_client::{EmailTransport, RetryPolicy};
 
     use claim::{assert_err, assert_ok};
     use fake::faker::internet::en::SafeEmail;
     use fake::faker::lorem::en::{Paragraph, Sentence};
     use fake::{Fake, Faker};
     use secrecy::Secret;
+    use std::time::Duration;
     use wiremock::matchers::{any, header, header_exists, method, path};
     use wiremock::Request;
     use wiremock::{Mock, MockServer, ResponseTemplate};
@@ -100,11 +166,26 @@ mod tests {
         }
     }
 
-    fn generate_email_client(uri: String) -> EmailClient {
-        EmailClient::new(
+    fn generate_email_client(uri: String) -> PostmarkEmailClient {
+        generate_email_client_with_retries(
+            uri,
+            RetryPolicy {
+                max_attempts: 1,
+                base_delay: Duration::from_millis(200),
+            },
+        )
+    }
+
+    fn generate_email_client_with_retries(
+        uri: String,
+        retry_policy: RetryPolicy,
+    ) -> PostmarkEmailClient {
+        PostmarkEmailClient::new(
             uri,
             SubscriberEmail::parse(SafeEmail().fake()).unwrap(),
             Secret::new(Faker.fake()),
+            Duration::from_secs(10),
+            retry_policy,
         )
     }
 
@@ -174,4 +255,36 @@ mod tests {
             .send_email(subscriber_email, &subject, &content, &content)
             .await;
     }
+
+    #[tokio::test]
+    async fn send_email_retries_once_after_a_transient_500_and_then_succeeds() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(any())
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let email_client = generate_email_client_with_retries(
+            mock_server.uri(),
+            RetryPolicy {
+                max_attempts: 2,
+                base_delay: Duration::from_millis(10),
+            },
+        );
+        let (subscriber_email, subject, content) = generate_email_fields();
+
+        let outcome = email_client
+            .send_email(subscriber_email, &subject, &content, &content)
+            .await;
+
+        assert_ok!(outcome);
+    }
 }
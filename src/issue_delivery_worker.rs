@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::domain::SubscriberEmail;
+use crate::email_client::EmailTransport;
+
+/// Attempts delivery of a single queued newsletter issue to a single
+/// subscriber.
+///
+/// The worker only ever sees `dyn EmailTransport`, so the concrete
+/// transport (Postmark, SMTP, ...) is entirely a startup-time decision
+/// driven by configuration.
+#[tracing::instrument(skip(email_transport, html_content, text_content))]
+pub async fn deliver_issue(
+    email_transport: &dyn EmailTransport,
+    recipient: SubscriberEmail,
+    subject: &str,
+    html_content: &str,
+    text_content: &str,
+) -> Result<(), anyhow::Error> {
+    email_transport
+        .send_email(recipient, subject, html_content, text_content)
+        .await?;
+    Ok(())
+}
+
+pub type SharedEmailTransport = Arc<dyn EmailTransport>;
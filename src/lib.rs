@@ -6,8 +6,12 @@ pub mod telemetry;
 pub mod domain;
 pub mod email_client;
 pub mod issue_delivery_worker;
+pub mod templates;
 pub mod utils;
 
 pub mod configuration;
 pub mod routes;
 pub mod startup;
+
+#[cfg(test)]
+pub(crate) mod test_helpers;
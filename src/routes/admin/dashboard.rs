@@ -1,53 +1,33 @@
 use actix_web::{http::header::ContentType, web, HttpResponse};
 use anyhow::Context;
+use askama::Template;
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::authentication::get_last_successful_login;
 use crate::session_state::TypedSession;
+use crate::templates::DashboardTemplate;
 use crate::utils::{e500, see_other};
 
 pub async fn admin_dashboard(
     session: TypedSession,
     pool: web::Data<PgPool>,
 ) -> Result<HttpResponse, actix_web::Error> {
-    let username = if let Some(user_id) = session.get_user_id().map_err(e500)? {
-        get_username(user_id, &pool).await.map_err(e500)?
-    } else {
-        return Ok(see_other("/login"));
+    let user_id = match session.get_user_id().map_err(e500)? {
+        Some(user_id) => user_id,
+        None => return Ok(see_other("/login")),
     };
+    let username = get_username(user_id, &pool).await.map_err(e500)?;
+    let last_login = get_last_successful_login(user_id, &pool)
+        .await
+        .map_err(e500)?
+        .map(|login| format!("{} at {}", login.ip, login.created_at.to_rfc2822()));
 
-    Ok(HttpResponse::Ok()
-        .content_type(ContentType::html())
-        .body(format!(
-            r#"
-            <!DOCTYPE html>
-            <html lang="en">
-            <head>
-              <title>Admin Dashboard</title>
-              <meta charset="UTF-8">
-              <meta http-equiv="content-type" content="text/html; charset=utf-8">
-              <meta name="viewport" content="width=device-width, initial-scale=1">
-            </head>
-            <body>
-              <p>Welcome {username}!</p>
-              <p>Available actions:</p>
-              <ol>
-                <li>
-                    <a href="/admin/password">Change password</a>
-                </li>
-                <li>
-                    <a href="/admin/newsletters">Publish a newsletter</a>
-                </li>
-                <li>
-                  <form name="logoutForm" action="/admin/logout" method="POST">
-                    <input type="submit" value="Logout">
-                  </form>
-                </li>
-              </ol>
-            </body>
-            </html>
-            "#
-        )))
+    let html = DashboardTemplate { username, last_login }
+        .render()
+        .map_err(e500)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::html()).body(html))
 }
 
 #[tracing::instrument(name = "Get username", skip(pool))]
@@ -0,0 +1,169 @@
+use actix_web::http::header::ContentType;
+use actix_web::{web, HttpResponse};
+use actix_web_flash_messages::{FlashMessage, IncomingFlashMessages};
+use askama::Template;
+use secrecy::Secret;
+use sqlx::PgPool;
+
+use crate::authentication::{validate_credentials, AuthError, Credentials};
+use crate::session_state::TypedSession;
+use crate::templates::AdminDeleteTemplate;
+use crate::utils::{e500, see_other};
+
+pub async fn admin_delete_form(
+    session: TypedSession,
+    flash_messages: IncomingFlashMessages,
+) -> Result<HttpResponse, actix_web::Error> {
+    if session.get_user_id().map_err(e500)?.is_none() {
+        return Ok(see_other("/login"));
+    };
+
+    let flash_messages = flash_messages.iter().map(|m| m.content().to_string()).collect();
+    let html = AdminDeleteTemplate { flash_messages }.render().map_err(e500)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::html()).body(html))
+}
+
+#[derive(serde::Deserialize)]
+pub struct AdminDeleteForm {
+    current_password: Secret<String>,
+    #[serde(default)]
+    confirmed: Option<String>,
+}
+
+#[tracing::instrument(name = "Delete admin account", skip(form, session, pool))]
+pub async fn admin_delete_submit(
+    form: web::Form<AdminDeleteForm>,
+    session: TypedSession,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let user_id = match session.get_user_id().map_err(e500)? {
+        Some(user_id) => user_id,
+        None => return Ok(see_other("/login")),
+    };
+
+    if form.confirmed.is_none() {
+        FlashMessage::error("You must confirm before deleting your account.").send();
+        return Ok(see_other("/admin/delete"));
+    }
+
+    let username = crate::routes::get_username(user_id, &pool)
+        .await
+        .map_err(e500)?;
+    let credentials = Credentials {
+        username,
+        password: form.0.current_password,
+    };
+
+    if let Err(e) = validate_credentials(credentials, &pool).await {
+        return match e {
+            AuthError::InvalidCredentials(_) => {
+                FlashMessage::error("The current password is incorrect.").send();
+                Ok(see_other("/admin/delete"))
+            }
+            AuthError::Unexpected(_) => Err(e500(e)),
+        };
+    }
+
+    delete_user(user_id, &pool).await.map_err(e500)?;
+
+    session.log_out();
+    FlashMessage::info("Your account has been deleted.").send();
+    Ok(see_other("/login"))
+}
+
+async fn delete_user(user_id: uuid::Uuid, pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        DELETE FROM users
+        WHERE user_id = $1
+        "#,
+        user_id,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::delete_user;
+    use crate::test_helpers::insert_test_user;
+    use chrono::{Duration, Utc};
+
+    /// Covers the full set of tables that reference `users(user_id)`, not
+    /// just a bare row - a previous version of this test only exercised the
+    /// latter and missed that `DELETE FROM users` failed with a foreign-key
+    /// violation for any account that had ever requested a password reset,
+    /// verified an email, or logged in.
+    #[sqlx::test]
+    async fn deleting_a_user_removes_their_row_and_dependents(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO password_reset_tokens (token_hash, user_id, expires_at)
+            VALUES ('some-hash', $1, $2)
+            "#,
+            user_id,
+            Utc::now() + Duration::hours(1),
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO email_verification_tokens (token, user_id)
+            VALUES ('some-token', $1)
+            "#,
+            user_id,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO login_events (user_id, ip, outcome)
+            VALUES ($1, '127.0.0.1', 'success')
+            "#,
+            user_id,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        delete_user(user_id, &pool).await.unwrap();
+
+        let row = sqlx::query!("SELECT user_id FROM users WHERE user_id = $1", user_id)
+            .fetch_optional(&pool)
+            .await
+            .unwrap();
+        assert!(row.is_none());
+
+        let reset_tokens = sqlx::query!(
+            "SELECT token_hash FROM password_reset_tokens WHERE user_id = $1",
+            user_id,
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert!(reset_tokens.is_empty());
+
+        let verification_tokens = sqlx::query!(
+            "SELECT token FROM email_verification_tokens WHERE user_id = $1",
+            user_id,
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert!(verification_tokens.is_empty());
+
+        let login_event = sqlx::query!("SELECT user_id FROM login_events WHERE ip = '127.0.0.1'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(login_event.user_id.is_none());
+    }
+}
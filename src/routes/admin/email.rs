@@ -0,0 +1,141 @@
+use actix_web::http::header::ContentType;
+use actix_web::{web, HttpResponse};
+use actix_web_flash_messages::{FlashMessage, IncomingFlashMessages};
+use askama::Template;
+use secrecy::Secret;
+use sqlx::PgPool;
+
+use crate::authentication::{validate_credentials, AuthError, Credentials};
+use crate::domain::SubscriberEmail;
+use crate::issue_delivery_worker::SharedEmailTransport;
+use super::email_verification::{require_verified_email, store_verification_token};
+use crate::session_state::TypedSession;
+use crate::templates::{AdminEmailTemplate, VerifyEmailContext};
+use crate::utils::{e500, see_other};
+
+pub async fn admin_email_form(
+    session: TypedSession,
+    flash_messages: IncomingFlashMessages,
+) -> Result<HttpResponse, actix_web::Error> {
+    if session.get_user_id().map_err(e500)?.is_none() {
+        return Ok(see_other("/login"));
+    };
+
+    let flash_messages = flash_messages.iter().map(|m| m.content().to_string()).collect();
+    let html = AdminEmailTemplate { flash_messages }.render().map_err(e500)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::html()).body(html))
+}
+
+#[derive(serde::Deserialize)]
+pub struct AdminEmailForm {
+    new_email: String,
+    current_password: Secret<String>,
+}
+
+#[tracing::instrument(name = "Change admin email", skip(form, session, pool, email_transport, base_url))]
+pub async fn admin_email_submit(
+    form: web::Form<AdminEmailForm>,
+    session: TypedSession,
+    pool: web::Data<PgPool>,
+    email_transport: web::Data<SharedEmailTransport>,
+    base_url: web::Data<crate::configuration::ApplicationBaseUrl>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let user_id = match session.get_user_id().map_err(e500)? {
+        Some(user_id) => user_id,
+        None => return Ok(see_other("/login")),
+    };
+
+    if let Some(redirect) = require_verified_email(user_id, &pool).await.map_err(e500)? {
+        return Ok(redirect);
+    }
+
+    let new_email = match SubscriberEmail::parse(form.0.new_email) {
+        Ok(email) => email,
+        Err(e) => {
+            FlashMessage::error(format!("That email address isn't valid: {e}")).send();
+            return Ok(see_other("/admin/email"));
+        }
+    };
+
+    let username = crate::routes::get_username(user_id, &pool)
+        .await
+        .map_err(e500)?;
+    let credentials = Credentials {
+        username,
+        password: form.0.current_password,
+    };
+
+    if let Err(e) = validate_credentials(credentials, &pool).await {
+        return match e {
+            AuthError::InvalidCredentials(_) => {
+                FlashMessage::error("The current password is incorrect.").send();
+                Ok(see_other("/admin/email"))
+            }
+            AuthError::Unexpected(_) => Err(e500(e)),
+        };
+    }
+
+    update_user_email(user_id, new_email.as_ref(), &pool)
+        .await
+        .map_err(e500)?;
+
+    let token = store_verification_token(user_id, &pool).await.map_err(e500)?;
+    let verify_link = format!("{}/admin/email/verify?token={}", base_url.0, token);
+    let (html_body, text_body) = VerifyEmailContext { verify_link }.render().map_err(e500)?;
+    let _ = email_transport
+        .send_email(new_email, "Verify your new email", &html_body, &text_body)
+        .await;
+
+    FlashMessage::info(
+        "Your email has been updated. Check your inbox to verify the new address.",
+    )
+    .send();
+    Ok(see_other("/admin/dashboard"))
+}
+
+/// Commits the new email and resets `verified` back to `false` - a changed
+/// address always needs to go through the double opt-in flow again.
+async fn update_user_email(
+    user_id: uuid::Uuid,
+    new_email: &str,
+    pool: &PgPool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET email = $1, verified = false
+        WHERE user_id = $2
+        "#,
+        new_email,
+        user_id,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::update_user_email;
+    use crate::test_helpers::insert_test_user;
+
+    #[sqlx::test]
+    async fn changing_email_resets_verified_to_false(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+
+        update_user_email(user_id, "new@example.com", &pool)
+            .await
+            .unwrap();
+
+        let row = sqlx::query!(
+            "SELECT email, verified FROM users WHERE user_id = $1",
+            user_id,
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(row.email, "new@example.com");
+        assert!(!row.verified);
+    }
+}
@@ -0,0 +1,158 @@
+use actix_web::{web, HttpResponse};
+use actix_web_flash_messages::FlashMessage;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::authentication::generate_verification_token;
+use crate::utils::{e500, see_other};
+
+/// Bounces the caller back to the dashboard with an error flash unless
+/// `user_id` has already completed email verification.
+///
+/// Used to gate account-management actions (starting with changing the
+/// email address itself) behind the double opt-in flow, so an attacker who
+/// grabs a session before the owner ever verifies can't quietly pivot the
+/// account to an address they control.
+pub async fn require_verified_email(
+    user_id: Uuid,
+    pool: &PgPool,
+) -> Result<Option<HttpResponse>, anyhow::Error> {
+    let row = sqlx::query!("SELECT verified FROM users WHERE user_id = $1", user_id)
+        .fetch_one(pool)
+        .await?;
+
+    if row.verified {
+        Ok(None)
+    } else {
+        FlashMessage::error("You must verify your current email address before changing it.")
+            .send();
+        Ok(Some(see_other("/admin/dashboard")))
+    }
+}
+
+/// Generates a single-use verification token for `user_id` and persists it,
+/// ready to be embedded in a verification link.
+pub async fn store_verification_token(user_id: Uuid, pool: &PgPool) -> Result<String, anyhow::Error> {
+    let token = generate_verification_token();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO email_verification_tokens (token, user_id)
+        VALUES ($1, $2)
+        "#,
+        token,
+        user_id,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(token)
+}
+
+#[derive(serde::Deserialize)]
+pub struct VerifyEmailQuery {
+    token: String,
+}
+
+#[tracing::instrument(name = "Verify admin email", skip(query, pool))]
+pub async fn verify_email(
+    query: web::Query<VerifyEmailQuery>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let user_id = match consume_verification_token(&query.token, &pool).await.map_err(e500)? {
+        Some(user_id) => user_id,
+        None => {
+            FlashMessage::error("That verification link is invalid or has already been used.")
+                .send();
+            return Ok(see_other("/admin/dashboard"));
+        }
+    };
+
+    FlashMessage::info("Your email address has been verified.").send();
+    Ok(see_other("/admin/dashboard"))
+}
+
+/// Looks the token up, deletes it so it can never be redeemed twice, and
+/// flips `verified` to true for its owner - all in one transaction.
+async fn consume_verification_token(
+    token: &str,
+    pool: &PgPool,
+) -> Result<Option<Uuid>, anyhow::Error> {
+    let mut transaction = pool.begin().await?;
+
+    let row = sqlx::query!(
+        r#"
+        DELETE FROM email_verification_tokens
+        WHERE token = $1
+        RETURNING user_id
+        "#,
+        token,
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+
+    let user_id = match row {
+        Some(row) => row.user_id,
+        None => return Ok(None),
+    };
+
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET verified = true
+        WHERE user_id = $1
+        "#,
+        user_id,
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+    Ok(Some(user_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{consume_verification_token, require_verified_email, store_verification_token};
+    use crate::test_helpers::{insert_test_user, insert_unverified_test_user};
+
+    #[sqlx::test]
+    async fn unknown_token_is_rejected(pool: sqlx::PgPool) {
+        let outcome = consume_verification_token("does-not-exist", &pool)
+            .await
+            .unwrap();
+        assert!(outcome.is_none());
+    }
+
+    #[sqlx::test]
+    async fn valid_token_verifies_the_user_exactly_once(pool: sqlx::PgPool) {
+        let user_id = insert_unverified_test_user(&pool).await;
+        let token = store_verification_token(user_id, &pool).await.unwrap();
+
+        let first = consume_verification_token(&token, &pool).await.unwrap();
+        assert_eq!(first, Some(user_id));
+
+        let row = sqlx::query!("SELECT verified FROM users WHERE user_id = $1", user_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(row.verified);
+
+        let second = consume_verification_token(&token, &pool).await.unwrap();
+        assert!(second.is_none());
+    }
+
+    #[sqlx::test]
+    async fn unverified_users_are_redirected(pool: sqlx::PgPool) {
+        let user_id = insert_unverified_test_user(&pool).await;
+        let outcome = require_verified_email(user_id, &pool).await.unwrap();
+        assert!(outcome.is_some());
+    }
+
+    #[sqlx::test]
+    async fn verified_users_pass_through(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+        let outcome = require_verified_email(user_id, &pool).await.unwrap();
+        assert!(outcome.is_none());
+    }
+}
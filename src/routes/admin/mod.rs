@@ -0,0 +1,11 @@
+mod dashboard;
+mod delete;
+mod email;
+mod email_verification;
+mod password;
+
+pub use dashboard::{admin_dashboard, get_username};
+pub use delete::{admin_delete_form, admin_delete_submit};
+pub use email::{admin_email_form, admin_email_submit};
+pub use email_verification::verify_email;
+pub use password::change_password_form;
@@ -0,0 +1,16 @@
+use actix_web::http::header::ContentType;
+use actix_web::HttpResponse;
+use actix_web_flash_messages::IncomingFlashMessages;
+use askama::Template;
+
+use crate::templates::LoginTemplate;
+use crate::utils::e500;
+
+pub async fn login_form(
+    flash_messages: IncomingFlashMessages,
+) -> Result<HttpResponse, actix_web::Error> {
+    let flash_messages = flash_messages.iter().map(|m| m.content().to_string()).collect();
+    let html = LoginTemplate { flash_messages }.render().map_err(e500)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::html()).body(html))
+}
@@ -0,0 +1,69 @@
+use actix_web::http::header::USER_AGENT;
+use actix_web::{web, HttpRequest, HttpResponse};
+use actix_web_flash_messages::FlashMessage;
+use secrecy::Secret;
+use sqlx::PgPool;
+
+use crate::authentication::{record_login_event, validate_credentials, AuthError, Credentials, LoginOutcome};
+use crate::configuration::Settings;
+use crate::session_state::TypedSession;
+use crate::utils::{client_ip, e500, see_other};
+
+#[derive(serde::Deserialize)]
+pub struct LoginForm {
+    username: String,
+    password: Secret<String>,
+}
+
+#[tracing::instrument(
+    name = "Login",
+    skip(form, req, pool, session, settings),
+    fields(username = tracing::field::Empty, user_id = tracing::field::Empty)
+)]
+pub async fn login(
+    form: web::Form<LoginForm>,
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    session: TypedSession,
+    settings: web::Data<Settings>,
+) -> Result<HttpResponse, actix_web::Error> {
+    tracing::Span::current().record("username", tracing::field::display(&form.username));
+
+    let ip = client_ip(&req, settings.trusted_ip_header.as_deref());
+    let user_agent = req
+        .headers()
+        .get(USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let credentials = Credentials {
+        username: form.0.username,
+        password: form.0.password,
+    };
+
+    match validate_credentials(credentials, &pool).await {
+        Ok(user_id) => {
+            tracing::Span::current().record("user_id", tracing::field::display(&user_id));
+            session.renew();
+            session.insert_user_id(user_id).map_err(e500)?;
+
+            record_login_event(Some(user_id), &ip, user_agent.as_deref(), LoginOutcome::Success, &pool)
+                .await
+                .map_err(e500)?;
+
+            Ok(see_other("/admin/dashboard"))
+        }
+        Err(e) => {
+            record_login_event(None, &ip, user_agent.as_deref(), LoginOutcome::Failure, &pool)
+                .await
+                .map_err(e500)?;
+
+            let message = match e {
+                AuthError::InvalidCredentials(_) => "Invalid username or password.",
+                AuthError::Unexpected(_) => "Something went wrong, please try again.",
+            };
+            FlashMessage::error(message).send();
+            Ok(see_other("/login"))
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod admin;
+mod login;
+mod password_reset;
+
+pub use admin::*;
+pub use login::*;
+pub use password_reset::*;
@@ -0,0 +1,112 @@
+use actix_web::http::header::ContentType;
+use actix_web::{web, HttpResponse};
+use actix_web_flash_messages::{FlashMessage, IncomingFlashMessages};
+use askama::Template;
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::authentication::{generate_token, hash_token};
+use crate::configuration::ApplicationBaseUrl;
+use crate::issue_delivery_worker::SharedEmailTransport;
+use crate::templates::{ForgotPasswordTemplate, PasswordResetEmailContext};
+use crate::utils::{e500, see_other};
+
+const GENERIC_FLASH_MESSAGE: &str =
+    "If an account with that username or email exists, we've sent a link to reset your password.";
+
+pub async fn forgot_password_form(
+    flash_messages: IncomingFlashMessages,
+) -> Result<HttpResponse, actix_web::Error> {
+    let flash_messages = flash_messages.iter().map(|m| m.content().to_string()).collect();
+    let html = ForgotPasswordTemplate { flash_messages }
+        .render()
+        .map_err(e500)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::html()).body(html))
+}
+
+#[derive(serde::Deserialize)]
+pub struct ForgotPasswordForm {
+    username_or_email: String,
+}
+
+#[tracing::instrument(name = "Request a password reset", skip(form, pool, email_transport, base_url))]
+pub async fn forgot_password_submit(
+    form: web::Form<ForgotPasswordForm>,
+    pool: web::Data<PgPool>,
+    email_transport: web::Data<SharedEmailTransport>,
+    base_url: web::Data<ApplicationBaseUrl>,
+) -> Result<HttpResponse, actix_web::Error> {
+    if let Some((user_id, email, username)) =
+        find_user_by_username_or_email(&form.username_or_email, &pool)
+            .await
+            .map_err(e500)?
+    {
+        let token = generate_token();
+        store_reset_token(user_id, &token, &pool).await.map_err(e500)?;
+
+        let reset_link = format!("{}/password/reset?token={}", base_url.0, token);
+        let (html_body, text_body) = PasswordResetEmailContext { username, reset_link }
+            .render()
+            .map_err(e500)?;
+
+        // Fire-and-forget, off the request path: the caller always gets the
+        // same generic response below regardless of whether the send (or
+        // its retry/backoff loop) succeeds, and it must not be awaited here
+        // either - a slow provider on the "account exists" branch would
+        // otherwise leak account existence through response latency.
+        let email_transport = email_transport.get_ref().clone();
+        tokio::spawn(async move {
+            let _ = email_transport
+                .send_email(email, "Reset your password", &html_body, &text_body)
+                .await;
+        });
+    }
+
+    FlashMessage::info(GENERIC_FLASH_MESSAGE).send();
+    Ok(see_other("/password/forgot"))
+}
+
+async fn find_user_by_username_or_email(
+    needle: &str,
+    pool: &PgPool,
+) -> Result<Option<(Uuid, crate::domain::SubscriberEmail, String)>, anyhow::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT user_id, email, username
+        FROM users
+        WHERE username = $1 OR email = $1
+        "#,
+        needle,
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(match row {
+        Some(row) => match crate::domain::SubscriberEmail::parse(row.email) {
+            Ok(email) => Some((row.user_id, email, row.username)),
+            Err(_) => None,
+        },
+        None => None,
+    })
+}
+
+async fn store_reset_token(user_id: Uuid, token: &str, pool: &PgPool) -> Result<(), anyhow::Error> {
+    let token_hash = hash_token(token);
+    let expires_at = Utc::now() + Duration::hours(1);
+
+    sqlx::query!(
+        r#"
+        INSERT INTO password_reset_tokens (token_hash, user_id, expires_at)
+        VALUES ($1, $2, $3)
+        "#,
+        token_hash,
+        user_id,
+        expires_at,
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,5 @@
+mod forgot;
+mod reset;
+
+pub use forgot::{forgot_password_form, forgot_password_submit};
+pub use reset::{reset_password_form, reset_password_submit};
@@ -0,0 +1,182 @@
+use actix_web::http::header::ContentType;
+use actix_web::{web, HttpResponse};
+use actix_web_flash_messages::{FlashMessage, IncomingFlashMessages};
+use askama::Template;
+use chrono::Utc;
+use secrecy::{ExposeSecret, Secret};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::authentication::{change_password, hash_token};
+use crate::templates::ResetPasswordTemplate;
+use crate::utils::{e500, see_other};
+
+#[derive(serde::Deserialize)]
+pub struct ResetPasswordQuery {
+    token: String,
+}
+
+pub async fn reset_password_form(
+    query: web::Query<ResetPasswordQuery>,
+    flash_messages: IncomingFlashMessages,
+) -> Result<HttpResponse, actix_web::Error> {
+    let flash_messages = flash_messages.iter().map(|m| m.content().to_string()).collect();
+    let html = ResetPasswordTemplate {
+        flash_messages,
+        token: query.token.clone(),
+    }
+    .render()
+    .map_err(e500)?;
+
+    Ok(HttpResponse::Ok().content_type(ContentType::html()).body(html))
+}
+
+#[derive(serde::Deserialize)]
+pub struct ResetPasswordForm {
+    token: String,
+    new_password: Secret<String>,
+    new_password_check: Secret<String>,
+}
+
+const MIN_PASSWORD_LENGTH: usize = 12;
+const MAX_PASSWORD_LENGTH: usize = 128;
+
+#[tracing::instrument(name = "Reset password", skip(form, pool))]
+pub async fn reset_password_submit(
+    form: web::Form<ResetPasswordForm>,
+    pool: web::Data<PgPool>,
+) -> Result<HttpResponse, actix_web::Error> {
+    if form.new_password.expose_secret() != form.new_password_check.expose_secret() {
+        FlashMessage::error(
+            "You entered two different new passwords - the field values must match.",
+        )
+        .send();
+        return Ok(see_other(&format!("/password/reset?token={}", form.token)));
+    }
+
+    let new_password_length = form.new_password.expose_secret().len();
+    if !(MIN_PASSWORD_LENGTH..=MAX_PASSWORD_LENGTH).contains(&new_password_length) {
+        FlashMessage::error(format!(
+            "Your new password must be between {MIN_PASSWORD_LENGTH} and {MAX_PASSWORD_LENGTH} characters long."
+        ))
+        .send();
+        return Ok(see_other(&format!("/password/reset?token={}", form.token)));
+    }
+
+    let token_hash = hash_token(&form.token);
+    let user_id = match consume_reset_token(&token_hash, &pool).await.map_err(e500)? {
+        Some(user_id) => user_id,
+        None => {
+            FlashMessage::error("That password reset link is invalid or has expired.").send();
+            return Ok(see_other("/password/forgot"));
+        }
+    };
+
+    change_password(user_id, form.0.new_password, &pool)
+        .await
+        .map_err(e500)?;
+
+    FlashMessage::info("Your password has been reset.").send();
+    Ok(see_other("/login"))
+}
+
+/// Looks the token up, rejects it if expired or already used, and marks it
+/// consumed in the same transaction so a token can never be redeemed twice.
+async fn consume_reset_token(token_hash: &str, pool: &PgPool) -> Result<Option<Uuid>, anyhow::Error> {
+    let mut transaction = pool.begin().await?;
+
+    let row = sqlx::query!(
+        r#"
+        SELECT user_id, expires_at, used_at
+        FROM password_reset_tokens
+        WHERE token_hash = $1
+        FOR UPDATE
+        "#,
+        token_hash,
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+
+    let row = match row {
+        Some(row) if row.used_at.is_none() && row.expires_at > Utc::now() => row,
+        _ => return Ok(None),
+    };
+
+    sqlx::query!(
+        r#"
+        UPDATE password_reset_tokens
+        SET used_at = now()
+        WHERE token_hash = $1
+        "#,
+        token_hash,
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+    Ok(Some(row.user_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::consume_reset_token;
+    use crate::authentication::hash_token;
+    use crate::test_helpers::insert_test_user;
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    async fn insert_reset_token(
+        pool: &sqlx::PgPool,
+        user_id: Uuid,
+        token: &str,
+        expires_at: chrono::DateTime<Utc>,
+    ) {
+        sqlx::query!(
+            r#"
+            INSERT INTO password_reset_tokens (token_hash, user_id, expires_at)
+            VALUES ($1, $2, $3)
+            "#,
+            hash_token(token),
+            user_id,
+            expires_at,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn unknown_token_is_rejected(pool: sqlx::PgPool) {
+        let outcome = consume_reset_token(&hash_token("does-not-exist"), &pool)
+            .await
+            .unwrap();
+        assert!(outcome.is_none());
+    }
+
+    #[sqlx::test]
+    async fn expired_token_is_rejected(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+        insert_reset_token(&pool, user_id, "a-token", Utc::now() - Duration::hours(1)).await;
+
+        let outcome = consume_reset_token(&hash_token("a-token"), &pool)
+            .await
+            .unwrap();
+        assert!(outcome.is_none());
+    }
+
+    #[sqlx::test]
+    async fn valid_token_is_consumed_exactly_once(pool: sqlx::PgPool) {
+        let user_id = insert_test_user(&pool).await;
+        insert_reset_token(&pool, user_id, "a-token", Utc::now() + Duration::hours(1)).await;
+
+        let first = consume_reset_token(&hash_token("a-token"), &pool)
+            .await
+            .unwrap();
+        assert_eq!(first, Some(user_id));
+
+        let second = consume_reset_token(&hash_token("a-token"), &pool)
+            .await
+            .unwrap();
+        assert!(second.is_none());
+    }
+}
@@ -0,0 +1,145 @@
+//! Compile-time checked HTML templates, rendered with Askama.
+//!
+//! Every admin page and every outgoing email has a typed context struct
+//! here instead of ad hoc `format!`/`writeln!` calls, so markup lives in
+//! `templates/*.html` and logic stays in Rust.
+use askama::Template;
+
+#[derive(Template)]
+#[template(path = "dashboard.html")]
+pub struct DashboardTemplate {
+    pub username: String,
+    pub last_login: Option<String>,
+}
+
+#[derive(Template)]
+#[template(path = "change_password.html")]
+pub struct ChangePasswordTemplate {
+    pub flash_messages: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "admin_email.html")]
+pub struct AdminEmailTemplate {
+    pub flash_messages: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "admin_delete.html")]
+pub struct AdminDeleteTemplate {
+    pub flash_messages: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "login.html")]
+pub struct LoginTemplate {
+    pub flash_messages: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "password_reset_forgot.html")]
+pub struct ForgotPasswordTemplate {
+    pub flash_messages: Vec<String>,
+}
+
+#[derive(Template)]
+#[template(path = "password_reset_form.html")]
+pub struct ResetPasswordTemplate {
+    pub flash_messages: Vec<String>,
+    pub token: String,
+}
+
+/// Shared context rendered into both the HTML and plaintext alternatives
+/// of the password-reset email, so the two bodies can never drift apart.
+pub struct PasswordResetEmailContext {
+    pub username: String,
+    pub reset_link: String,
+}
+
+#[derive(Template)]
+#[template(path = "email/password_reset.html")]
+pub struct PasswordResetEmailHtml<'a> {
+    pub username: &'a str,
+    pub reset_link: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "email/password_reset.txt")]
+pub struct PasswordResetEmailText<'a> {
+    pub username: &'a str,
+    pub reset_link: &'a str,
+}
+
+impl PasswordResetEmailContext {
+    pub fn render(&self) -> Result<(String, String), askama::Error> {
+        let html = PasswordResetEmailHtml {
+            username: &self.username,
+            reset_link: &self.reset_link,
+        }
+        .render()?;
+        let text = PasswordResetEmailText {
+            username: &self.username,
+            reset_link: &self.reset_link,
+        }
+        .render()?;
+        Ok((html, text))
+    }
+}
+
+/// Shared context rendered into both alternatives of the double opt-in
+/// email-verification message, so they can't drift apart.
+pub struct VerifyEmailContext {
+    pub verify_link: String,
+}
+
+#[derive(Template)]
+#[template(path = "email/verify_email.html")]
+pub struct VerifyEmailHtml<'a> {
+    pub verify_link: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "email/verify_email.txt")]
+pub struct VerifyEmailText<'a> {
+    pub verify_link: &'a str,
+}
+
+impl VerifyEmailContext {
+    pub fn render(&self) -> Result<(String, String), askama::Error> {
+        let html = VerifyEmailHtml {
+            verify_link: &self.verify_link,
+        }
+        .render()?;
+        let text = VerifyEmailText {
+            verify_link: &self.verify_link,
+        }
+        .render()?;
+        Ok((html, text))
+    }
+}
+
+#[derive(Template)]
+#[template(path = "email/confirmation.html")]
+pub struct ConfirmationEmailHtml<'a> {
+    pub confirmation_link: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "email/confirmation.txt")]
+pub struct ConfirmationEmailText<'a> {
+    pub confirmation_link: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "email/newsletter.html")]
+pub struct NewsletterEmailHtml<'a> {
+    pub title: &'a str,
+    pub html_content: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "email/newsletter.txt")]
+pub struct NewsletterEmailText<'a> {
+    pub title: &'a str,
+    pub text_content: &'a str,
+}
@@ -0,0 +1,32 @@
+//! Fixtures shared by the `#[sqlx::test]` suites scattered across
+//! `routes::admin`, `routes::password_reset` and `authentication`, so each
+//! one tests against the same `users` row shape instead of hand-rolling
+//! slightly different inserts.
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub async fn insert_test_user(pool: &PgPool) -> Uuid {
+    insert_user(pool, true).await
+}
+
+pub async fn insert_unverified_test_user(pool: &PgPool) -> Uuid {
+    insert_user(pool, false).await
+}
+
+async fn insert_user(pool: &PgPool, verified: bool) -> Uuid {
+    let user_id = Uuid::new_v4();
+    sqlx::query!(
+        r#"
+        INSERT INTO users (user_id, username, password_hash, email, verified)
+        VALUES ($1, $2, 'irrelevant', $3, $4)
+        "#,
+        user_id,
+        Uuid::new_v4().to_string(),
+        format!("{}@example.com", Uuid::new_v4()),
+        verified,
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+    user_id
+}
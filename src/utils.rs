@@ -0,0 +1,44 @@
+use actix_web::http::header::LOCATION;
+use actix_web::{HttpRequest, HttpResponse};
+
+/// Returns a `303 See Other` response redirecting to `location`.
+pub fn see_other(location: &str) -> HttpResponse {
+    HttpResponse::SeeOther()
+        .insert_header((LOCATION, location))
+        .finish()
+}
+
+/// Converts an opaque error into an `actix_web::Error` with a `500`
+/// status code, logging the full error chain.
+pub fn e500<T>(e: T) -> actix_web::Error
+where
+    T: std::fmt::Debug + std::fmt::Display + 'static,
+{
+    actix_web::error::ErrorInternalServerError(e)
+}
+
+/// Converts an opaque error into an `actix_web::Error` with a `400`
+/// status code.
+pub fn e400<T>(e: T) -> actix_web::Error
+where
+    T: std::fmt::Debug + std::fmt::Display + 'static,
+{
+    actix_web::error::ErrorBadRequest(e)
+}
+
+/// Resolves the client's IP, preferring `trusted_header` (set by a reverse
+/// proxy we trust, e.g. `X-Forwarded-For`) and falling back to the
+/// connection's `peer_addr` otherwise.
+pub fn client_ip(req: &HttpRequest, trusted_header: Option<&str>) -> String {
+    if let Some(header_name) = trusted_header {
+        if let Some(value) = req.headers().get(header_name).and_then(|v| v.to_str().ok()) {
+            if let Some(first) = value.split(',').next() {
+                return first.trim().to_string();
+            }
+        }
+    }
+
+    req.peer_addr()
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}